@@ -22,10 +22,12 @@ fn test_df() -> Result<(), Box<dyn Error>> {
     let file_path = args().nth(1).unwrap();
     let sheet_name = args().nth(2).unwrap();
 
-    let mut df = CalamineToPolarsReader::new(file_path)
+    let mut reader = CalamineToPolarsReader::new(file_path);
+    let header_policy = reader.header_policy();
+    let mut df = reader
         .open_sheet(sheet_name)
         .unwrap()
-        .to_frame_all_str()?;
+        .to_frame_all_str(header_policy)?;
 
     // Before convenient casting
     println!("{:#?}", df);
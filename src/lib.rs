@@ -1,12 +1,131 @@
 use std::collections::HashMap;
 use std::{error::Error, fmt::Display, fs::File, io::BufReader, path::Path};
 
-use calamine::{CellType, Data, DataType, Error as CalamineError, Range, Reader, Xlsx};
+use calamine::{CellType, Data, DataType, Error as CalamineError, Ods, Range, Reader, Xls, Xlsb, Xlsx};
 use polars::frame::DataFrame;
 use polars::prelude::*; // enum Column
 
 pub struct CalamineToPolarsReader {
-    workbook: Xlsx<BufReader<File>>,
+    workbook: Workbook,
+    header_policy: HeaderPolicy,
+}
+
+/// Holds whichever calamine reader matches the workbook's file extension,
+/// so [`CalamineToPolarsReader`] can work with xlsx, xls, ods and xlsb
+/// files uniformly. Every variant yields a `Range<Data>`, so callers never
+/// need to know which concrete reader is behind it.
+pub enum Workbook {
+    Xlsx(Xlsx<BufReader<File>>),
+    Xls(Xls<BufReader<File>>),
+    Ods(Ods<BufReader<File>>),
+    Xlsb(Xlsb<BufReader<File>>),
+}
+
+impl Workbook {
+    /// Picks the reader based on the file's extension (case-insensitive);
+    /// anything unrecognized (including no extension) is opened as xlsx.
+    fn open<P: AsRef<Path>>(file_name: P) -> Result<Self, CalamineError> {
+        let path = file_name.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase())
+            .unwrap_or_default();
+
+        match extension.as_str() {
+            "xls" => Ok(Workbook::Xls(calamine::open_workbook(path)?)),
+            "ods" => Ok(Workbook::Ods(calamine::open_workbook(path)?)),
+            "xlsb" => Ok(Workbook::Xlsb(calamine::open_workbook(path)?)),
+            _ => Ok(Workbook::Xlsx(calamine::open_workbook(path)?)),
+        }
+    }
+
+    fn worksheet_range(&mut self, sheet_name: &str) -> Result<Range<Data>, CalamineError> {
+        match self {
+            Workbook::Xlsx(workbook) => workbook.worksheet_range(sheet_name),
+            Workbook::Xls(workbook) => workbook.worksheet_range(sheet_name),
+            Workbook::Ods(workbook) => workbook.worksheet_range(sheet_name),
+            Workbook::Xlsb(workbook) => workbook.worksheet_range(sheet_name),
+        }
+    }
+
+    fn sheet_names(&self) -> Vec<String> {
+        match self {
+            Workbook::Xlsx(workbook) => workbook.sheet_names(),
+            Workbook::Xls(workbook) => workbook.sheet_names(),
+            Workbook::Ods(workbook) => workbook.sheet_names(),
+            Workbook::Xlsb(workbook) => workbook.sheet_names(),
+        }
+    }
+}
+
+/// Controls how the `to_frame_*` methods handle duplicate or empty header
+/// cells when building column names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HeaderPolicy {
+    /// Return an error as soon as a duplicate header is seen.
+    Fail,
+    /// Disambiguate a duplicate header the same way as
+    /// [`HeaderPolicy::Numeric`] (Polars requires unique column names, so
+    /// truly keeping a duplicate as-is isn't possible), but log a warning
+    /// to stderr for each one instead of silently renaming it.
+    Allow,
+    /// Append an incrementing numeric suffix (`foo`, `foo1`, `foo2`, ...)
+    /// until the header is unique. This is the default.
+    #[default]
+    Numeric,
+}
+
+/// Appends an incrementing numeric suffix to `name` (`foo1`, `foo2`, ...)
+/// until the result isn't in `seen`.
+fn disambiguate(name: &str, seen: &std::collections::HashSet<String>) -> String {
+    let mut suffix = 1;
+    let mut candidate = format!("{name}{suffix}");
+    while seen.contains(&candidate) {
+        suffix += 1;
+        candidate = format!("{name}{suffix}");
+    }
+    candidate
+}
+
+/// Build unique column names from a sheet's header row, applying
+/// `policy` to duplicates and synthesizing `column{idx}` for blank cells.
+/// Shared by all `to_frame_*` methods so they agree on one naming scheme.
+fn build_headers<'a, I, T>(first_row: I, policy: HeaderPolicy) -> Result<Vec<String>, Box<dyn Error>>
+where
+    I: IntoIterator<Item = &'a T>,
+    T: Display + 'a,
+{
+    let mut seen = std::collections::HashSet::<String>::new();
+    let mut headers = Vec::new();
+
+    for (idx, cell) in first_row.into_iter().enumerate() {
+        let raw = cell.to_string();
+        let name = if raw.is_empty() {
+            format!("column{idx}")
+        } else {
+            raw
+        };
+
+        let name = if seen.contains(&name) {
+            match policy {
+                HeaderPolicy::Fail => return Err(format!("duplicate header {name:?}").into()),
+                HeaderPolicy::Allow => {
+                    let candidate = disambiguate(&name, &seen);
+                    eprintln!("duplicate header {name:?} renamed to {candidate:?} at column {idx}");
+                    candidate
+                }
+                HeaderPolicy::Numeric => disambiguate(&name, &seen),
+            }
+        } else {
+            name
+        };
+
+        seen.insert(name.clone());
+        headers.push(name);
+    }
+
+    Ok(headers)
 }
 
 /// Implelemt pandas style type catsing API for specified column(s).
@@ -46,224 +165,682 @@ pub trait ToPolarsDataFrame {
     /// This method assumes the input calamine Excel data
     /// has headers (column titles).
     /// It tries to convert Excel data into strongly-typed DataFrame.
-    fn to_frame_auto_type(&mut self) -> Result<DataFrame, Box<dyn Error>>;
+    ///
+    /// Each column's dtype is inferred by scanning `sample_size` rows (or
+    /// every row, if `None`) and widening along Bool ⊂ Int64 ⊂ Float64 ⊂
+    /// String as needed; empty cells don't affect the guess. Values that
+    /// don't fit the resolved dtype become null instead of panicking.
+    fn to_frame_auto_type(
+        &mut self,
+        header_policy: HeaderPolicy,
+        sample_size: Option<usize>,
+    ) -> Result<DataFrame, Box<dyn Error>>;
     /// Convert to DataFrame but everything's a String
-    fn to_frame_all_str(&self) -> Result<DataFrame, Box<dyn Error>>;
-    /// Pre-defined dtype(s) for upcoming DataFrame
-    fn to_frame_with_types(&self, column_dtype: &HashMap<&str, polars::datatypes::DataType>);
+    /// except a column whose non-empty cells are all datetimes, which
+    /// becomes a Date column (all values at midnight) or a Datetime column.
+    fn to_frame_all_str(&self, header_policy: HeaderPolicy) -> Result<DataFrame, Box<dyn Error>>;
+    /// Pre-defined dtype(s) for upcoming DataFrame.
+    ///
+    /// `column_dtype` maps a header name to the Polars dtype it should be
+    /// parsed into while reading, so the column never has to be read as
+    /// String and cast afterwards. Headers not present in the map are
+    /// read as plain strings (datetime cells included, unlike
+    /// [`ToPolarsDataFrame::to_frame_all_str`], which auto-detects and
+    /// types datetime columns on its own).
+    /// Cells that fail to parse into the declared dtype become null instead
+    /// of panicking; a warning is printed to stderr for each one.
+    fn to_frame_with_types(
+        &self,
+        column_dtype: &HashMap<&str, polars::datatypes::DataType>,
+        header_policy: HeaderPolicy,
+    ) -> Result<DataFrame, Box<dyn Error>>;
+    /// Like [`Self::to_frame_all_str`], but pulls rows off calamine's row
+    /// iterator `batch_size` at a time and builds the `DataFrame` one batch
+    /// at a time, instead of collecting every row into a `Vec` up front;
+    /// the result is returned as a `LazyFrame` so callers can push
+    /// `filter`/`select`/`with_columns` down the plan. Datetime cells are
+    /// stringified rather than auto-detected per column, since every batch
+    /// needs the same schema to stack onto the previous one.
+    ///
+    /// `self` is a calamine `Range`, which already holds every cell of the
+    /// sheet in memory once the sheet is opened — calamine has no
+    /// lower-level streaming read to build this from, and the returned
+    /// `DataFrame`/`LazyFrame` itself holds the whole sheet once built, the
+    /// same as [`Self::to_frame_all_str`]. What batching buys here is
+    /// bounding the *conversion* working set (the per-cell `String`
+    /// buffers) to one batch at a time rather than the whole sheet at
+    /// once; it is not a reduction in overall peak memory below one
+    /// sheet's worth of data. The final `DataFrame` is rechunked before
+    /// being wrapped as a `LazyFrame`, so a small `batch_size` doesn't
+    /// leave it fragmented into many small chunks.
+    fn to_lazy_frame_all_str(
+        &self,
+        header_policy: HeaderPolicy,
+        batch_size: usize,
+    ) -> Result<LazyFrame, Box<dyn Error>>;
+    /// Like [`Self::to_frame_with_types`], but pulls rows off calamine's
+    /// row iterator `batch_size` at a time and builds the `DataFrame` one
+    /// batch at a time, returning it as a `LazyFrame`. See
+    /// [`Self::to_lazy_frame_all_str`] for what batching does and doesn't
+    /// buy in terms of memory.
+    fn to_lazy_frame_with_types(
+        &self,
+        column_dtype: &HashMap<&str, polars::datatypes::DataType>,
+        header_policy: HeaderPolicy,
+        batch_size: usize,
+    ) -> Result<LazyFrame, Box<dyn Error>>;
 }
 
-impl<T> ToPolarsDataFrame for Range<T>
-where
-    T: DataType + CellType + Display,
-{
-    fn to_frame_with_types(&self, _column_dtype: &HashMap<&str, polars::datatypes::DataType>) {
-        todo!();
+/// Broad grouping of the Polars dtypes this crate knows how to parse
+/// directly from calamine cells. Exact variants (e.g. `Int32` vs `Int64`)
+/// are reached by parsing into the canonical member of the group and then
+/// casting, the same way [`CastColumnType::with_types`] does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TypeCategory {
+    Int,
+    Float,
+    Bool,
+    Date,
+    Datetime,
+    Str,
+}
+
+fn categorize_dtype(dtype: &polars::datatypes::DataType) -> TypeCategory {
+    use polars::datatypes::DataType::*;
+    match dtype {
+        Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 => TypeCategory::Int,
+        Float32 | Float64 => TypeCategory::Float,
+        Boolean => TypeCategory::Bool,
+        Date => TypeCategory::Date,
+        Datetime(_, _) => TypeCategory::Datetime,
+        _ => TypeCategory::Str,
     }
+}
 
-    fn to_frame_all_str(&self) -> Result<DataFrame, Box<dyn Error>> {
-        let all_rows = self.rows().collect::<Vec<_>>();
+/// Converts an Excel/Lotus serial date (days since 1899-12-30, the same
+/// epoch calamine's own `as_datetime()` uses) into a `NaiveDateTime`, for
+/// columns that are declared as a date/datetime dtype but read back as a
+/// bare float.
+fn excel_serial_to_datetime(serial: f64) -> Option<chrono::NaiveDateTime> {
+    let seconds = (serial - 25569.0) * 86400.0;
+    chrono::DateTime::from_timestamp(seconds as i64, 0).map(|dt| dt.naive_utc())
+}
 
-        // iterating or duplicate headers
-        let mut header_counts = HashMap::<String, usize>::new();
-        let headers: Vec<String> = all_rows
-            .first()
-            .ok_or("No data")?
-            .iter()
-            .map(|cell| {
-                let count = header_counts.entry(cell.to_string()).or_insert(0);
-                let name = if *count > 0 {
-                    format!("{}_duplicated_{}", cell, count)
-                } else {
-                    cell.to_string()
-                };
-                *count += 1;
-                name
-            })
-            .collect();
+/// Whether every one of `times` (skipping `None`s) falls exactly on
+/// midnight, i.e. the column only carries a date, not a time of day.
+fn all_midnight<'a, I: IntoIterator<Item = &'a Option<chrono::NaiveDateTime>>>(times: I) -> bool {
+    times
+        .into_iter()
+        .flatten()
+        .all(|dt| dt.time() == chrono::NaiveTime::MIN)
+}
 
-        // pre allocated column memory
-        let mut columns: Vec<Vec<String>> = vec![vec![]; headers.len()];
-        columns.iter_mut().for_each(|v| v.reserve(all_rows.len()));
+/// Builds a Date or Datetime `Column` from a column's worth of parsed
+/// timestamps, narrowing to Date when every value sits at midnight.
+fn build_datetime_column(name: &str, times: Vec<Option<chrono::NaiveDateTime>>) -> Column {
+    if all_midnight(&times) {
+        let dates: Vec<Option<chrono::NaiveDate>> =
+            times.into_iter().map(|dt| dt.map(|dt| dt.date())).collect();
+        Column::new(name.into(), dates)
+    } else {
+        Column::new(name.into(), times)
+    }
+}
 
-        // iterating through all rows
-        for row in &all_rows[1..] {
-            row.iter().enumerate().for_each(|(col_idx, cell)| {
+/// Widens two type categories to the narrower-compatible common type along
+/// the Bool ⊂ Int64 ⊂ Float64 ⊂ String lattice. Only meaningful for that
+/// lattice; Date/Datetime never participate in widening.
+fn widen(a: TypeCategory, b: TypeCategory) -> TypeCategory {
+    fn rank(category: TypeCategory) -> u8 {
+        match category {
+            TypeCategory::Bool => 0,
+            TypeCategory::Int => 1,
+            TypeCategory::Float => 2,
+            TypeCategory::Str => 3,
+            TypeCategory::Date | TypeCategory::Datetime => 3,
+        }
+    }
+    if rank(a) >= rank(b) {
+        a
+    } else {
+        b
+    }
+}
+
+/// Per-column accumulator used while parsing a sheet into declared dtypes.
+enum ColumnBuffer {
+    Int(Vec<Option<i64>>),
+    Float(Vec<Option<f64>>),
+    Bool(Vec<Option<bool>>),
+    Date(Vec<Option<chrono::NaiveDateTime>>),
+    Datetime(Vec<Option<chrono::NaiveDateTime>>),
+    Str(Vec<String>),
+}
+
+impl ColumnBuffer {
+    fn new(category: TypeCategory) -> Self {
+        match category {
+            TypeCategory::Int => ColumnBuffer::Int(Vec::new()),
+            TypeCategory::Float => ColumnBuffer::Float(Vec::new()),
+            TypeCategory::Bool => ColumnBuffer::Bool(Vec::new()),
+            TypeCategory::Date => ColumnBuffer::Date(Vec::new()),
+            TypeCategory::Datetime => ColumnBuffer::Datetime(Vec::new()),
+            TypeCategory::Str => ColumnBuffer::Str(Vec::new()),
+        }
+    }
+
+    /// Parse `cell` and push it, recording a warning (row, header, column
+    /// index) instead of panicking when the cell can't be coerced.
+    fn push<T>(&mut self, cell: &T, row_index: usize, col_idx: usize, header: &str, warnings: &mut Vec<String>)
+    where
+        T: DataType + CellType + Display,
+    {
+        match self {
+            ColumnBuffer::Int(col) => {
+                let value = match cell {
+                    c if c.is_int() => c.get_int(),
+                    c if c.is_bool() => c.get_bool().map(|b| b as i64),
+                    c if c.is_float() => match c.get_float() {
+                        Some(f) => {
+                            warnings.push(format!(
+                                "row {row_index}, col {header} (column index {col_idx}): truncated float {f} to int"
+                            ));
+                            Some(f as i64)
+                        }
+                        None => None,
+                    },
+                    c if c.is_empty() => return col.push(None),
+                    c => c.to_string().trim().parse::<i64>().ok(),
+                };
+                if value.is_none() {
+                    warnings.push(format!(
+                        "row {row_index}, col {header} (column index {col_idx}): expected int, got {cell}"
+                    ));
+                }
+                col.push(value);
+            }
+            ColumnBuffer::Float(col) => {
+                let value = match cell {
+                    c if c.is_float() => c.get_float(),
+                    c if c.is_int() => c.get_int().map(|i| i as f64),
+                    c if c.is_bool() => c.get_bool().map(|b| if b { 1.0 } else { 0.0 }),
+                    c if c.is_empty() => return col.push(None),
+                    c => c.to_string().trim().parse::<f64>().ok(),
+                };
+                if value.is_none() {
+                    warnings.push(format!(
+                        "row {row_index}, col {header} (column index {col_idx}): expected float, got {cell}"
+                    ));
+                }
+                col.push(value);
+            }
+            ColumnBuffer::Bool(col) => {
+                let value = match cell {
+                    c if c.is_bool() => c.get_bool(),
+                    c if c.is_empty() => return col.push(None),
+                    c => c.to_string().trim().parse::<bool>().ok(),
+                };
+                if value.is_none() {
+                    warnings.push(format!(
+                        "row {row_index}, col {header} (column index {col_idx}): expected bool, got {cell}"
+                    ));
+                }
+                col.push(value);
+            }
+            ColumnBuffer::Date(col) | ColumnBuffer::Datetime(col) => {
+                let value = match cell {
+                    c if c.is_datetime() => c.as_datetime(),
+                    c if c.is_float() => excel_serial_to_datetime(c.get_float().unwrap_or_default()),
+                    c if c.is_empty() => return col.push(None),
+                    _ => None,
+                };
+                if value.is_none() {
+                    warnings.push(format!(
+                        "row {row_index}, col {header} (column index {col_idx}): expected date/datetime, got {cell}"
+                    ));
+                }
+                col.push(value);
+            }
+            ColumnBuffer::Str(col) => {
                 let cell_str = match cell {
                     c if c.is_datetime() => c
                         .as_datetime()
                         .map(|dt| dt.to_string())
-                        .unwrap_or_else(|| String::new()),
+                        .unwrap_or_default(),
                     _ => cell.to_string(),
                 };
-                columns[col_idx].push(cell_str);
-            });
+                col.push(cell_str);
+            }
         }
+    }
 
-        // list of `Column`s
-        let columns: Vec<Column> = columns
+    fn into_column(self, name: &str) -> Column {
+        match self {
+            ColumnBuffer::Int(col) => Column::new(name.into(), col),
+            ColumnBuffer::Float(col) => Column::new(name.into(), col),
+            ColumnBuffer::Bool(col) => Column::new(name.into(), col),
+            ColumnBuffer::Date(col) => Column::new(
+                name.into(),
+                col.into_iter().map(|dt| dt.map(|dt| dt.date())).collect::<Vec<_>>(),
+            ),
+            ColumnBuffer::Datetime(col) => Column::new(name.into(), col),
+            ColumnBuffer::Str(col) => Column::new(name.into(), col),
+        }
+    }
+}
+
+impl<T> ToPolarsDataFrame for Range<T>
+where
+    T: DataType + CellType + Display,
+{
+    fn to_frame_with_types(
+        &self,
+        column_dtype: &HashMap<&str, polars::datatypes::DataType>,
+        header_policy: HeaderPolicy,
+    ) -> Result<DataFrame, Box<dyn Error>> {
+        let all_rows = self.rows().collect::<Vec<_>>();
+
+        let headers = build_headers(all_rows.first().ok_or("No data")?.iter(), header_policy)?;
+
+        let categories: Vec<TypeCategory> = headers
+            .iter()
+            .map(|header| {
+                column_dtype
+                    .get(header.as_str())
+                    .map(categorize_dtype)
+                    .unwrap_or(TypeCategory::Str)
+            })
+            .collect();
+
+        let mut buffers: Vec<ColumnBuffer> = categories.iter().map(|c| ColumnBuffer::new(*c)).collect();
+        let mut warnings: Vec<String> = Vec::new();
+
+        for (row_index, row) in all_rows[1..].iter().enumerate() {
+            for (col_idx, cell) in row.iter().enumerate() {
+                buffers[col_idx].push(cell, row_index, col_idx, &headers[col_idx], &mut warnings);
+            }
+        }
+
+        if !warnings.is_empty() {
+            eprintln!("to_frame_with_types: {} cell(s) could not be parsed into their declared dtype and were set to null (or, for floats in an int column, truncated):", warnings.len());
+            for warning in &warnings {
+                eprintln!("  {warning}");
+            }
+        }
+
+        let columns: Vec<Column> = buffers
             .into_iter()
-            .zip(headers)
-            .map(|(col, name)| Column::new((&name).into(), col))
+            .zip(&headers)
+            .map(|(buffer, name)| buffer.into_column(name))
             .collect();
 
-        // constructing DataFrame
-        let df = DataFrame::new(columns)?;
+        let mut df = DataFrame::new(columns)?;
+
+        // Cast each declared column to its exact requested dtype (e.g. Int32
+        // rather than the canonical Int64 used while parsing).
+        let exact_types: Vec<(&str, polars::datatypes::DataType)> = headers
+            .iter()
+            .filter_map(|header| {
+                column_dtype
+                    .get(header.as_str())
+                    .map(|dtype| (header.as_str(), dtype.clone()))
+            })
+            .collect();
+        if !exact_types.is_empty() {
+            df = df.with_types(&exact_types)?;
+        }
 
         Ok(df)
     }
 
-    fn to_frame_auto_type(&mut self) -> Result<DataFrame, Box<dyn Error>> {
-        let mut columns: Vec<Column> = Vec::new();
-        let mut column_types: Vec<polars::datatypes::DataType> = Vec::new();
-        // Headers
-        let headers: Vec<String> = self
-            .rows()
-            .next()
-            .ok_or("No data")?
+    fn to_lazy_frame_with_types(
+        &self,
+        column_dtype: &HashMap<&str, polars::datatypes::DataType>,
+        header_policy: HeaderPolicy,
+        batch_size: usize,
+    ) -> Result<LazyFrame, Box<dyn Error>> {
+        let batch_size = batch_size.max(1);
+        let mut rows = self.rows();
+        let header_row = rows.next().ok_or("No data")?;
+        let headers = build_headers(header_row.iter(), header_policy)?;
+
+        let categories: Vec<TypeCategory> = headers
+            .iter()
+            .map(|header| {
+                column_dtype
+                    .get(header.as_str())
+                    .map(categorize_dtype)
+                    .unwrap_or(TypeCategory::Str)
+            })
+            .collect();
+        let exact_types: Vec<(&str, polars::datatypes::DataType)> = headers
             .iter()
-            .map(|cell| cell.to_string())
+            .filter_map(|header| {
+                column_dtype
+                    .get(header.as_str())
+                    .map(|dtype| (header.as_str(), dtype.clone()))
+            })
             .collect();
 
-        // Vec<String> for each column
-        for _ in 0..headers.len() {
-            column_types.push(polars::datatypes::DataType::Null);
+        // Empty, correctly-typed frame to vstack every batch onto; this
+        // also gives the right schema back when the sheet has no rows.
+        let mut df = DataFrame::new(
+            categories
+                .iter()
+                .zip(&headers)
+                .map(|(category, name)| ColumnBuffer::new(*category).into_column(name))
+                .collect(),
+        )?;
+        if !exact_types.is_empty() {
+            df = df.with_types(&exact_types)?;
         }
 
-        // The first row of the ramaining part decides each column's data type
-        for (col_index, cell) in self.rows().nth(1).unwrap().iter().enumerate() {
-            let header = headers[col_index].as_str();
-            match cell {
-                c if c.is_int() => {
-                    column_types[col_index] = polars::datatypes::DataType::Int64;
-                    columns.push(Column::new(header.into(), [cell.get_int().unwrap()]));
+        // Pull `batch_size` rows at a time straight off calamine's row
+        // iterator instead of collecting every row up front, so only one
+        // batch's worth of rows is ever held outside the `Range` itself.
+        let mut warnings: Vec<String> = Vec::new();
+        let mut row_index = 0usize;
+        loop {
+            let mut buffers: Vec<ColumnBuffer> =
+                categories.iter().map(|category| ColumnBuffer::new(*category)).collect();
+
+            let mut rows_in_batch = 0usize;
+            for row in rows.by_ref().take(batch_size) {
+                for (col_idx, cell) in row.iter().enumerate() {
+                    buffers[col_idx].push(cell, row_index, col_idx, &headers[col_idx], &mut warnings);
                 }
-                c if c.is_float() => {
-                    column_types[col_index] = polars::datatypes::DataType::Float64;
-                    columns.push(Column::new(header.into(), [cell.get_float().unwrap()]));
+                row_index += 1;
+                rows_in_batch += 1;
+            }
+            if rows_in_batch == 0 {
+                break;
+            }
+
+            let columns: Vec<Column> = buffers
+                .into_iter()
+                .zip(&headers)
+                .map(|(buffer, name)| buffer.into_column(name))
+                .collect();
+            let mut chunk_df = DataFrame::new(columns)?;
+            if !exact_types.is_empty() {
+                chunk_df = chunk_df.with_types(&exact_types)?;
+            }
+            df.vstack_mut(&chunk_df)?;
+        }
+
+        if !warnings.is_empty() {
+            eprintln!("to_lazy_frame_with_types: {} cell(s) could not be parsed into their declared dtype and were set to null (or, for floats in an int column, truncated):", warnings.len());
+            for warning in &warnings {
+                eprintln!("  {warning}");
+            }
+        }
+
+        // Each `vstack_mut` above appends a chunk's worth of new Series
+        // chunks rather than copying into the existing ones, so a small
+        // `batch_size` would otherwise leave `df` fragmented into many
+        // small chunks that slow down every downstream operation.
+        df.rechunk();
+
+        Ok(df.lazy())
+    }
+
+    fn to_lazy_frame_all_str(
+        &self,
+        header_policy: HeaderPolicy,
+        batch_size: usize,
+    ) -> Result<LazyFrame, Box<dyn Error>> {
+        let batch_size = batch_size.max(1);
+        let mut rows = self.rows();
+        let header_row = rows.next().ok_or("No data")?;
+        let headers = build_headers(header_row.iter(), header_policy)?;
+
+        let mut df = DataFrame::new(
+            headers
+                .iter()
+                .map(|name| Column::new_empty(name.into(), &polars::datatypes::DataType::String))
+                .collect(),
+        )?;
+
+        // Pull `batch_size` rows at a time straight off calamine's row
+        // iterator instead of collecting every row up front, so only one
+        // batch's worth of rows is ever held outside the `Range` itself.
+        loop {
+            let mut str_columns: Vec<Vec<String>> = vec![Vec::with_capacity(batch_size); headers.len()];
+            let mut rows_in_batch = 0usize;
+            for row in rows.by_ref().take(batch_size) {
+                row.iter().enumerate().for_each(|(col_idx, cell)| {
+                    let cell_str = match cell {
+                        c if c.is_datetime() => c
+                            .as_datetime()
+                            .map(|dt| dt.to_string())
+                            .unwrap_or_default(),
+                        _ => cell.to_string(),
+                    };
+                    str_columns[col_idx].push(cell_str);
+                });
+                rows_in_batch += 1;
+            }
+            if rows_in_batch == 0 {
+                break;
+            }
+
+            let chunk_columns: Vec<Column> = str_columns
+                .into_iter()
+                .zip(&headers)
+                .map(|(col, name)| Column::new(name.into(), col))
+                .collect();
+            let chunk_df = DataFrame::new(chunk_columns)?;
+            df.vstack_mut(&chunk_df)?;
+        }
+
+        // See the matching comment in `to_lazy_frame_with_types`: without
+        // this, a small `batch_size` leaves `df` fragmented into many small
+        // chunks instead of speeding anything up.
+        df.rechunk();
+
+        Ok(df.lazy())
+    }
+
+    fn to_frame_all_str(&self, header_policy: HeaderPolicy) -> Result<DataFrame, Box<dyn Error>> {
+        let all_rows = self.rows().collect::<Vec<_>>();
+
+        let headers = build_headers(all_rows.first().ok_or("No data")?.iter(), header_policy)?;
+        let data_rows = &all_rows[1..];
+
+        // A column whose non-empty cells are all datetimes gets a real
+        // Date/Datetime dtype instead of being stringified; everything
+        // else stays a String, as the name promises. A column needs at
+        // least one non-empty datetime cell to qualify, so one that's
+        // empty across every row (or unseen entirely) falls back to
+        // String instead of becoming a column of null dates.
+        let mut saw_datetime_cell = vec![false; headers.len()];
+        let mut saw_conflicting_cell = vec![false; headers.len()];
+        for row in data_rows {
+            for (col_idx, cell) in row.iter().enumerate() {
+                if cell.is_empty() {
+                    continue;
                 }
-                c if c.is_bool() => {
-                    column_types[col_index] = polars::datatypes::DataType::Boolean;
-                    columns.push(Column::new(header.into(), [cell.get_bool().unwrap()]));
+                if cell.is_datetime() {
+                    saw_datetime_cell[col_idx] = true;
+                } else {
+                    saw_conflicting_cell[col_idx] = true;
                 }
-                c if c.is_string() => {
-                    column_types[col_index] = polars::datatypes::DataType::String;
-                    columns.push(Column::new(header.into(), [cell.get_string().unwrap()]));
+            }
+        }
+        let is_datetime_col: Vec<bool> = saw_datetime_cell
+            .iter()
+            .zip(&saw_conflicting_cell)
+            .map(|(&saw_datetime, &saw_conflict)| saw_datetime && !saw_conflict)
+            .collect();
+
+        // pre allocated column memory
+        let mut str_columns: Vec<Vec<String>> = vec![vec![]; headers.len()];
+        let mut datetime_columns: Vec<Vec<Option<chrono::NaiveDateTime>>> =
+            vec![vec![]; headers.len()];
+        str_columns.iter_mut().for_each(|v| v.reserve(data_rows.len()));
+
+        // iterating through all rows
+        for row in data_rows {
+            row.iter().enumerate().for_each(|(col_idx, cell)| {
+                if is_datetime_col[col_idx] {
+                    datetime_columns[col_idx].push(cell.as_datetime());
+                } else {
+                    let cell_str = match cell {
+                        c if c.is_datetime() => c
+                            .as_datetime()
+                            .map(|dt| dt.to_string())
+                            .unwrap_or_default(),
+                        _ => cell.to_string(),
+                    };
+                    str_columns[col_idx].push(cell_str);
                 }
-                c if c.is_empty() => {
-                    column_types[col_index] = polars::datatypes::DataType::Null;
-                    columns.push(Column::new(
-                        header.into(),
-                        [cell.get_string().unwrap_or_default()],
-                    ));
+            });
+        }
+
+        // list of `Column`s
+        let columns: Vec<Column> = headers
+            .iter()
+            .enumerate()
+            .map(|(col_idx, name)| {
+                if is_datetime_col[col_idx] {
+                    build_datetime_column(name, std::mem::take(&mut datetime_columns[col_idx]))
+                } else {
+                    Column::new(name.into(), std::mem::take(&mut str_columns[col_idx]))
                 }
-                c if c.is_error() => {
-                    panic!("This cell is error. The first row of the ramaining part decides each column's data type");
+            })
+            .collect();
+
+        // constructing DataFrame
+        let df = DataFrame::new(columns)?;
+
+        Ok(df)
+    }
+
+    fn to_frame_auto_type(
+        &mut self,
+        header_policy: HeaderPolicy,
+        sample_size: Option<usize>,
+    ) -> Result<DataFrame, Box<dyn Error>> {
+        let all_rows = self.rows().collect::<Vec<_>>();
+        let headers = build_headers(all_rows.first().ok_or("No data")?.iter(), header_policy)?;
+        let data_rows = &all_rows[1..];
+
+        let sample_len = sample_size.unwrap_or(data_rows.len()).min(data_rows.len());
+        let sample_rows = &data_rows[..sample_len];
+
+        // A column whose sampled, non-empty cells are all datetimes gets a
+        // Date/Datetime dtype, the same way `to_frame_all_str` decides it.
+        // A column needs at least one observed non-empty datetime cell to
+        // qualify, so one that's empty across the sample (or unseen
+        // entirely) resolves to String rather than a column of null dates.
+        // Everything else is widened along Bool ⊂ Int64 ⊂ Float64 ⊂ String:
+        // empty cells don't affect the guess.
+        let mut saw_datetime_cell = vec![false; headers.len()];
+        let mut saw_conflicting_cell = vec![false; headers.len()];
+        let mut categories: Vec<Option<TypeCategory>> = vec![None; headers.len()];
+        for row in sample_rows {
+            for (col_idx, cell) in row.iter().enumerate() {
+                if cell.is_empty() {
+                    continue;
                 }
-                _ => {
-                    panic!("Unknown error. The first row of the ramaining part decides each column's data type");
+                if cell.is_datetime() {
+                    saw_datetime_cell[col_idx] = true;
+                } else {
+                    saw_conflicting_cell[col_idx] = true;
                 }
+                let candidate = if cell.is_bool() {
+                    TypeCategory::Bool
+                } else if cell.is_int() {
+                    TypeCategory::Int
+                } else if cell.is_float() {
+                    TypeCategory::Float
+                } else {
+                    TypeCategory::Str
+                };
+                categories[col_idx] = Some(match categories[col_idx] {
+                    None => candidate,
+                    Some(existing) => widen(existing, candidate),
+                });
             }
-            // todo!()
         }
-        dbg!(DataFrame::new(columns.clone()).unwrap());
+        let is_datetime_col: Vec<bool> = saw_datetime_cell
+            .iter()
+            .zip(&saw_conflicting_cell)
+            .map(|(&saw_datetime, &saw_conflict)| saw_datetime && !saw_conflict)
+            .collect();
 
-        // iterating through all rows remaining
-        for (row_index, row) in self.rows().skip(2).enumerate() {
+        let mut buffers: Vec<ColumnBuffer> = (0..headers.len())
+            .map(|col_idx| {
+                ColumnBuffer::new(if is_datetime_col[col_idx] {
+                    TypeCategory::Datetime
+                } else {
+                    categories[col_idx].unwrap_or(TypeCategory::Str)
+                })
+            })
+            .collect();
+        let mut warnings: Vec<String> = Vec::new();
+
+        for (row_index, row) in data_rows.iter().enumerate() {
             for (col_idx, cell) in row.iter().enumerate() {
-                let header = headers[col_idx].as_str();
-                match cell {
-                    c if c.is_int() => {
-                        let new_column = Column::new(header.into(), [c.get_int()]);
-
-                        let append_result = columns[col_idx].append(&new_column);
-                        match append_result {
-                            Ok(_) => {}
-                            Err(_) => {
-                                eprintln!(
-                                    "{}",
-                                    format!("row {row_index}, col {header} (column index {col_idx}): expected int").as_str()
-                                );
-                                dbg!(&new_column);
-                            }
-                        }
-                        /*
-                        columns[col_idx].append(&new_series).expect(
-                                format!("row {row_index}, col {header} (column index {col_idx}): expected int").as_str()
-                        );
-                        */
-                    }
-                    c if c.is_float() => {
-                        let new_column = Column::new(header.into(), [c.get_float()]);
-
-                        let append_result = columns[col_idx].append(&new_column);
-                        match append_result {
-                            Ok(_) => {}
-                            Err(_) => {
-                                eprintln!(
-                                    "{}",
-                                    format!("row {row_index}, col {header} (column index {col_idx}): expected float").as_str()
-                                );
-                                dbg!(&new_column);
-                            }
-                        }
-                        /*
-                        columns[col_idx].append(&new_series).expect(
-                                format!("row {row_index}, col {header} (column index {col_idx}): expected float").as_str()
-                        );
-                        */
-                        /*
-                        columns[col_idx].append(&new_series).expect(
-                            format!("row {row_index}, col {header} (column index {col_idx}): expected float").as_str(),
-                        );
-                        */
-                    }
-                    c if c.is_bool() => {
-                        let new_column = Column::new(header.into(), [c.get_bool()]);
-                        columns[col_idx].append(&new_column).expect(
-                            format!("row {row_index}, col {header} (column index {col_idx}): expected bool").as_str(),
-                        );
-                    }
-                    c if c.is_string() => {
-                        let new_column = Column::new(header.into(), [c.get_string()]);
-                        columns[col_idx].append(&new_column).expect(
-                            format!("row {row_index}, col {header} (column index {col_idx}): expected string").as_str(),
-                        );
-                    }
-                    c if c.is_empty() => {
-                        let new_column = Column::new_empty(
-                            header.into(),
-                            polars::datatypes::DataType::Null.as_ref(),
-                        );
-                        columns[col_idx].append(&new_column).unwrap();
-                    }
-                    _ => {
-                        panic!("Error when reading all data...")
-                    }
-                }
+                buffers[col_idx].push(cell, row_index, col_idx, &headers[col_idx], &mut warnings);
             }
         }
 
-        let df = DataFrame::new(columns)?;
+        if !warnings.is_empty() {
+            eprintln!(
+                "to_frame_auto_type: {} cell(s) did not match their inferred column type and were set to null (or, for floats in an int column, truncated):",
+                warnings.len()
+            );
+            for warning in &warnings {
+                eprintln!("  {warning}");
+            }
+        }
 
-        Ok(df)
+        let columns: Vec<Column> = buffers
+            .into_iter()
+            .zip(&headers)
+            .map(|(buffer, name)| match buffer {
+                ColumnBuffer::Datetime(times) => build_datetime_column(name, times),
+                other => other.into_column(name),
+            })
+            .collect();
+
+        Ok(DataFrame::new(columns)?)
     }
 }
 
 impl CalamineToPolarsReader {
     //
-    pub fn open_workbook<P: AsRef<Path>>(file_name: P) -> Xlsx<BufReader<File>> {
-        let workbook: Xlsx<_> =
-            calamine::open_workbook(file_name).expect("Could not open workbook");
-        workbook
+    pub fn open_workbook<P: AsRef<Path>>(file_name: P) -> Workbook {
+        Workbook::open(file_name).expect("Could not open workbook")
     }
 
     pub fn new<P: AsRef<Path>>(file_name: P) -> Self {
         Self {
             workbook: CalamineToPolarsReader::open_workbook(file_name),
+            header_policy: HeaderPolicy::default(),
         }
     }
 
+    /// Names of every sheet in the workbook, regardless of its format
+    /// (xlsx, xls, ods, xlsb).
+    pub fn sheet_names(&self) -> Vec<String> {
+        self.workbook.sheet_names()
+    }
+
+    /// Set how duplicate/empty headers are handled by the `to_frame_*`
+    /// methods. Defaults to [`HeaderPolicy::Numeric`].
+    pub fn with_header_policy(mut self, header_policy: HeaderPolicy) -> Self {
+        self.header_policy = header_policy;
+        self
+    }
+
+    /// The currently configured [`HeaderPolicy`], to pass along to the
+    /// `to_frame_*` methods on the `Range` returned by [`Self::open_sheet`].
+    pub fn header_policy(&self) -> HeaderPolicy {
+        self.header_policy
+    }
+
     //
     pub fn open_sheet<S: AsRef<str>>(&mut self, sheet_name: S) -> Option<Range<Data>> {
         if let Ok(sheet_range) = self.workbook.worksheet_range(sheet_name.as_ref()) {
@@ -293,3 +870,97 @@ impl CalamineToPolarsReader {
         return Err(CalamineError::Msg("Missing column name"));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disambiguate_finds_the_first_free_suffix() {
+        let mut seen = std::collections::HashSet::new();
+        seen.insert("foo".to_string());
+        seen.insert("foo1".to_string());
+        assert_eq!(disambiguate("foo", &seen), "foo2");
+    }
+
+    #[test]
+    fn build_headers_numeric_suffixes_duplicates() {
+        let headers = build_headers(
+            &vec!["foo".to_string(), "foo".to_string(), "foo".to_string()],
+            HeaderPolicy::Numeric,
+        )
+        .unwrap();
+        assert_eq!(headers, vec!["foo", "foo1", "foo2"]);
+    }
+
+    #[test]
+    fn build_headers_allow_disambiguates_instead_of_erroring() {
+        let headers = build_headers(
+            &vec!["foo".to_string(), "foo".to_string()],
+            HeaderPolicy::Allow,
+        )
+        .unwrap();
+        assert_eq!(headers, vec!["foo", "foo1"]);
+    }
+
+    #[test]
+    fn build_headers_fail_errors_on_duplicate() {
+        let result = build_headers(&vec!["foo".to_string(), "foo".to_string()], HeaderPolicy::Fail);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn build_headers_synthesizes_names_for_blank_cells() {
+        let headers = build_headers(
+            &vec!["".to_string(), "foo".to_string(), "".to_string()],
+            HeaderPolicy::Numeric,
+        )
+        .unwrap();
+        assert_eq!(headers, vec!["column0", "foo", "column2"]);
+    }
+
+    #[test]
+    fn widen_follows_bool_int_float_str_lattice() {
+        assert_eq!(widen(TypeCategory::Bool, TypeCategory::Int), TypeCategory::Int);
+        assert_eq!(widen(TypeCategory::Int, TypeCategory::Bool), TypeCategory::Int);
+        assert_eq!(widen(TypeCategory::Int, TypeCategory::Float), TypeCategory::Float);
+        assert_eq!(widen(TypeCategory::Float, TypeCategory::Str), TypeCategory::Str);
+        assert_eq!(widen(TypeCategory::Bool, TypeCategory::Bool), TypeCategory::Bool);
+        assert_eq!(widen(TypeCategory::Str, TypeCategory::Int), TypeCategory::Str);
+    }
+
+    #[test]
+    fn categorize_dtype_groups_exact_width_variants() {
+        use polars::datatypes::DataType::*;
+        assert_eq!(categorize_dtype(&Int32), TypeCategory::Int);
+        assert_eq!(categorize_dtype(&UInt64), TypeCategory::Int);
+        assert_eq!(categorize_dtype(&Float32), TypeCategory::Float);
+        assert_eq!(categorize_dtype(&Boolean), TypeCategory::Bool);
+        assert_eq!(categorize_dtype(&Date), TypeCategory::Date);
+        assert_eq!(
+            categorize_dtype(&Datetime(polars::datatypes::TimeUnit::Milliseconds, None)),
+            TypeCategory::Datetime
+        );
+        assert_eq!(categorize_dtype(&String), TypeCategory::Str);
+    }
+
+    #[test]
+    fn all_midnight_is_true_only_without_a_time_of_day_component() {
+        let midnight = chrono::NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let with_time = chrono::NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_hms_opt(13, 0, 0)
+            .unwrap();
+        assert!(all_midnight(&[Some(midnight), None]));
+        assert!(!all_midnight(&[Some(midnight), Some(with_time)]));
+    }
+
+    #[test]
+    fn excel_serial_to_datetime_anchors_serial_25569_to_the_unix_epoch() {
+        let dt = excel_serial_to_datetime(25569.0).unwrap();
+        assert_eq!(dt, chrono::DateTime::from_timestamp(0, 0).unwrap().naive_utc());
+    }
+}